@@ -0,0 +1,52 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::crypto::{HASH_SIZE, HMAC_KEY_SIZE};
+use alloc::vec::Vec;
+
+/// Size in bytes of an AES-256 key.
+pub const AES_256_KEY_SIZE: usize = 32;
+/// Size in bytes of the CBC initialization vector.
+pub const AES_256_BLOCK_SIZE: usize = 16;
+
+/// Authenticated symmetric encryption used by the CTAP2 client-PIN protocol.
+///
+/// Implementations encrypt with AES-256-CBC and authenticate with HMAC-SHA256
+/// over the IV and ciphertext (encrypt-then-MAC), so callers never need to
+/// decrypt data whose tag hasn't already been verified. `enc_key` and
+/// `mac_key` are separate: CTAP2 derives them as distinct `aesKey`/`hmacKey`
+/// outputs from the shared secret precisely so the same key material is
+/// never used for both primitives.
+pub trait Aes256 {
+    /// Encrypts `plaintext` under `enc_key` with a freshly generated random IV.
+    ///
+    /// `plaintext` may be any length; it is always PKCS7-padded, even when
+    /// already a multiple of `AES_256_BLOCK_SIZE` bytes, so that decryption
+    /// never has to guess whether padding was applied. Returns
+    /// `AES_256_BLOCK_SIZE` bytes of IV followed by the ciphertext.
+    fn encrypt(enc_key: &[u8; AES_256_KEY_SIZE], plaintext: &[u8]) -> Option<Vec<u8>>;
+
+    /// Decrypts `iv_and_ciphertext` (as produced by [`Aes256::encrypt`]) under `enc_key`.
+    fn decrypt(enc_key: &[u8; AES_256_KEY_SIZE], iv_and_ciphertext: &[u8]) -> Option<Vec<u8>>;
+
+    /// Computes an HMAC-SHA256 tag over `iv_and_ciphertext` under `mac_key`.
+    fn authenticate(mac_key: &[u8; HMAC_KEY_SIZE], iv_and_ciphertext: &[u8]) -> [u8; HASH_SIZE];
+
+    /// Verifies an HMAC-SHA256 tag over `iv_and_ciphertext` under `mac_key` in constant time.
+    fn verify(
+        mac_key: &[u8; HMAC_KEY_SIZE],
+        iv_and_ciphertext: &[u8],
+        mac: &[u8; HASH_SIZE],
+    ) -> bool;
+}
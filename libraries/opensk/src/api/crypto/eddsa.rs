@@ -0,0 +1,58 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rng256::Rng256;
+
+/// Size in bytes of an Ed25519 seed, public key coordinate, or compressed point.
+pub const ED_FIELD_SIZE: usize = 32;
+/// Size in bytes of a detached Ed25519 signature.
+pub const ED_SIGNATURE_SIZE: usize = 64;
+
+/// EdDSA over Curve25519 (COSE algorithm `EdDSA`, alg -8).
+///
+/// This is a peer of [`super::ecdsa::Ecdsa`], not a variant bolted onto it: FIDO2
+/// authenticators may advertise either algorithm, and both are modeled the same way.
+pub trait Eddsa {
+    type SecretKey: SecretKey<PublicKey = Self::PublicKey, Signature = Self::Signature>;
+    type PublicKey: PublicKey<Signature = Self::Signature>;
+    type Signature: Signature;
+}
+
+pub trait SecretKey {
+    type PublicKey;
+    type Signature;
+
+    fn random(rng: &mut impl Rng256) -> Self;
+    fn from_seed(seed: &[u8; ED_FIELD_SIZE]) -> Self;
+    fn public_key(&self) -> Self::PublicKey;
+    fn sign(&self, message: &[u8]) -> Self::Signature;
+    fn to_seed(&self, seed: &mut [u8; ED_FIELD_SIZE]);
+}
+
+pub trait PublicKey {
+    type Signature;
+
+    fn from_bytes(bytes: &[u8; ED_FIELD_SIZE]) -> Option<Self>
+    where
+        Self: Sized;
+    fn verify(&self, message: &[u8], signature: &Self::Signature) -> bool;
+    fn to_bytes(&self, bytes: &mut [u8; ED_FIELD_SIZE]);
+}
+
+pub trait Signature {
+    fn from_bytes(bytes: &[u8; ED_SIGNATURE_SIZE]) -> Option<Self>
+    where
+        Self: Sized;
+    fn to_bytes(&self) -> [u8; ED_SIGNATURE_SIZE];
+}
@@ -0,0 +1,40 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::crypto::EC_FIELD_SIZE;
+
+/// BIP32-style hierarchical deterministic derivation of per-RP P-256 signing keys.
+///
+/// A single stored seed (private key + chain code) can regenerate a stable tree of
+/// child private keys, so the authenticator doesn't need to persist one key per
+/// credential: it only needs to remember the derivation index.
+pub trait Hd256: Sized {
+    /// Creates the master node from a 32-byte seed. The chain code is derived
+    /// from the seed the same way the private key is, so two different seeds
+    /// never collide on the resulting chain code.
+    fn from_seed(seed: &[u8; EC_FIELD_SIZE]) -> Self;
+
+    /// The node's private scalar, usable directly with `ecdsa::SecretKey::from_slice`.
+    fn private_key(&self) -> [u8; EC_FIELD_SIZE];
+
+    /// The node's 32-byte chain code.
+    fn chain_code(&self) -> [u8; EC_FIELD_SIZE];
+
+    /// Derives child node `index`. `hardened` selects the hardened derivation
+    /// variant, which mixes in the parent private key instead of its public key.
+    ///
+    /// Returns `None` on the (astronomically unlikely) case where the derived
+    /// scalar is invalid, per BIP32: callers should retry with the next index.
+    fn derive_child(&self, index: u32, hardened: bool) -> Option<Self>;
+}
@@ -0,0 +1,708 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This cryptography implementation is a second alternative for our own library, backed by a
+//! native OpenSSL/BoringSSL installation instead of the pure-Rust `p256`/`ed25519-dalek` stack
+//! used by [`super::rust_crypto`].
+//!
+//! You can use it with the `openssl_crypto` feature. It is a drop-in replacement for
+//! [`super::rust_crypto::SoftwareCrypto`] at the [`Crypto`] trait boundary: platforms that ship a
+//! native crypto library can pick this provider instead without changing any caller.
+
+use crate::api::crypto::aes256::{Aes256, AES_256_BLOCK_SIZE, AES_256_KEY_SIZE};
+use crate::api::crypto::eddsa::{self, ED_FIELD_SIZE, ED_SIGNATURE_SIZE};
+use crate::api::crypto::hkdf256::Hkdf256;
+use crate::api::crypto::hmac256::Hmac256;
+use crate::api::crypto::sha256::Sha256;
+use crate::api::crypto::{
+    ecdh, ecdsa, Crypto, EC_FIELD_SIZE, EC_SIGNATURE_SIZE, HASH_SIZE, HMAC_KEY_SIZE,
+    TRUNCATED_HMAC_SIZE,
+};
+use alloc::vec::Vec;
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::derive::Deriver;
+use openssl::ec::{EcGroup, EcKey, EcPoint};
+use openssl::hash::MessageDigest;
+use openssl::md::Md;
+use openssl::nid::Nid;
+use openssl::pkey::{Id, PKey, Private, Public};
+use openssl::pkey_ctx::PkeyCtx;
+use openssl::rand::rand_bytes;
+use openssl::sign::{Signer, Verifier};
+use openssl::symm::{Cipher, Crypter, Mode};
+use rng256::Rng256;
+
+fn p256_group() -> EcGroup {
+    EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap()
+}
+
+pub struct OpenSslCrypto;
+pub struct OpenSslEcdh;
+pub struct OpenSslEcdsa;
+pub struct OpenSslAes256;
+pub struct OpenSslEddsa;
+
+impl Crypto for OpenSslCrypto {
+    type Ecdh = OpenSslEcdh;
+    type Ecdsa = OpenSslEcdsa;
+    type Sha256 = OpenSslSha256;
+    type Hmac256 = OpenSslHmac256;
+    type Hkdf256 = OpenSslHkdf256;
+    type Aes256 = OpenSslAes256;
+    type Eddsa = OpenSslEddsa;
+}
+
+impl ecdh::Ecdh for OpenSslEcdh {
+    type SecretKey = OpenSslEcdhSecretKey;
+    type PublicKey = OpenSslEcdhPublicKey;
+    type SharedSecret = OpenSslEcdhSharedSecret;
+}
+
+pub struct OpenSslEcdhSecretKey {
+    key: EcKey<Private>,
+}
+
+impl ecdh::SecretKey for OpenSslEcdhSecretKey {
+    type PublicKey = OpenSslEcdhPublicKey;
+    type SharedSecret = OpenSslEcdhSharedSecret;
+
+    fn random(_rng: &mut impl Rng256) -> Self {
+        let key = EcKey::generate(&p256_group()).unwrap();
+        OpenSslEcdhSecretKey { key }
+    }
+
+    fn public_key(&self) -> Self::PublicKey {
+        let key = EcKey::from_public_key(&p256_group(), self.key.public_key()).unwrap();
+        OpenSslEcdhPublicKey { key }
+    }
+
+    fn diffie_hellman(&self, public_key: &OpenSslEcdhPublicKey) -> Self::SharedSecret {
+        let our_key = PKey::from_ec_key(self.key.clone()).unwrap();
+        let their_key = PKey::from_ec_key(public_key.key.clone()).unwrap();
+        let mut deriver = Deriver::new(&our_key).unwrap();
+        deriver.set_peer(&their_key).unwrap();
+        let secret = deriver.derive_to_vec().unwrap();
+        let mut shared_secret = [0; EC_FIELD_SIZE];
+        shared_secret.copy_from_slice(&secret);
+        OpenSslEcdhSharedSecret { shared_secret }
+    }
+}
+
+pub struct OpenSslEcdhPublicKey {
+    key: EcKey<Public>,
+}
+
+impl ecdh::PublicKey for OpenSslEcdhPublicKey {
+    fn from_coordinates(x: &[u8; EC_FIELD_SIZE], y: &[u8; EC_FIELD_SIZE]) -> Option<Self> {
+        let mut ctx = BigNumContext::new().ok()?;
+        let x = BigNum::from_slice(x).ok()?;
+        let y = BigNum::from_slice(y).ok()?;
+        let group = p256_group();
+        let mut point = EcPoint::new(&group).ok()?;
+        point
+            .set_affine_coordinates_gfp(&group, &x, &y, &mut ctx)
+            .ok()?;
+        let key = EcKey::from_public_key(&group, &point).ok()?;
+        Some(OpenSslEcdhPublicKey { key })
+    }
+
+    fn to_coordinates(&self, x: &mut [u8; EC_FIELD_SIZE], y: &mut [u8; EC_FIELD_SIZE]) {
+        to_coordinates(&self.key, x, y);
+    }
+}
+
+pub struct OpenSslEcdhSharedSecret {
+    shared_secret: [u8; EC_FIELD_SIZE],
+}
+
+impl ecdh::SharedSecret for OpenSslEcdhSharedSecret {
+    fn raw_secret_bytes(&self) -> [u8; EC_FIELD_SIZE] {
+        self.shared_secret
+    }
+}
+
+impl ecdsa::Ecdsa for OpenSslEcdsa {
+    type SecretKey = OpenSslEcdsaSecretKey;
+    type PublicKey = OpenSslEcdsaPublicKey;
+    type Signature = OpenSslEcdsaSignature;
+}
+
+pub struct OpenSslEcdsaSecretKey {
+    key: EcKey<Private>,
+}
+
+impl ecdsa::SecretKey for OpenSslEcdsaSecretKey {
+    type PublicKey = OpenSslEcdsaPublicKey;
+    type Signature = OpenSslEcdsaSignature;
+
+    fn random(_rng: &mut impl Rng256) -> Self {
+        let key = EcKey::generate(&p256_group()).unwrap();
+        OpenSslEcdsaSecretKey { key }
+    }
+
+    fn from_slice(bytes: &[u8; EC_FIELD_SIZE]) -> Option<Self> {
+        let private_number = BigNum::from_slice(bytes).ok()?;
+        let group = p256_group();
+        let mut ctx = BigNumContext::new().ok()?;
+        let mut public_point = EcPoint::new(&group).ok()?;
+        public_point
+            .mul_generator(&group, &private_number, &ctx)
+            .ok()?;
+        let key = EcKey::from_private_components(&group, &private_number, &public_point).ok()?;
+        Some(OpenSslEcdsaSecretKey { key })
+    }
+
+    fn public_key(&self) -> Self::PublicKey {
+        let key = EcKey::from_public_key(&p256_group(), self.key.public_key()).unwrap();
+        OpenSslEcdsaPublicKey { key }
+    }
+
+    fn sign(&self, message: &[u8]) -> Self::Signature {
+        let pkey = PKey::from_ec_key(self.key.clone()).unwrap();
+        let mut signer = Signer::new(MessageDigest::sha256(), &pkey).unwrap();
+        let der = signer.sign_oneshot_to_vec(message).unwrap();
+        let sig = openssl::ecdsa::EcdsaSig::from_der(&der).unwrap();
+        OpenSslEcdsaSignature::new_normalized(sig)
+    }
+
+    fn to_slice(&self, bytes: &mut [u8; EC_FIELD_SIZE]) {
+        let private_number = self
+            .key
+            .private_key()
+            .to_vec_padded(EC_FIELD_SIZE as i32)
+            .unwrap();
+        bytes.copy_from_slice(&private_number);
+    }
+}
+
+pub struct OpenSslEcdsaPublicKey {
+    key: EcKey<Public>,
+}
+
+impl ecdsa::PublicKey for OpenSslEcdsaPublicKey {
+    type Signature = OpenSslEcdsaSignature;
+
+    fn from_coordinates(x: &[u8; EC_FIELD_SIZE], y: &[u8; EC_FIELD_SIZE]) -> Option<Self> {
+        let mut ctx = BigNumContext::new().ok()?;
+        let x = BigNum::from_slice(x).ok()?;
+        let y = BigNum::from_slice(y).ok()?;
+        let group = p256_group();
+        let mut point = EcPoint::new(&group).ok()?;
+        point
+            .set_affine_coordinates_gfp(&group, &x, &y, &mut ctx)
+            .ok()?;
+        let key = EcKey::from_public_key(&group, &point).ok()?;
+        Some(OpenSslEcdsaPublicKey { key })
+    }
+
+    fn verify(&self, message: &[u8], signature: &Self::Signature) -> bool {
+        let pkey = PKey::from_ec_key(self.key.clone()).unwrap();
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &pkey).unwrap();
+        verifier
+            .verify_oneshot(&signature.der, message)
+            .unwrap_or(false)
+    }
+
+    fn to_coordinates(&self, x: &mut [u8; EC_FIELD_SIZE], y: &mut [u8; EC_FIELD_SIZE]) {
+        to_coordinates(&self.key, x, y);
+    }
+}
+
+impl OpenSslEcdsaPublicKey {
+    /// Like [`ecdsa::PublicKey::verify`], but additionally rejects signatures
+    /// whose `s` isn't already in its normalized, low-S form.
+    pub fn verify_strict(&self, message: &[u8], signature: &OpenSslEcdsaSignature) -> bool {
+        signature.is_normalized() && self.verify(message, signature)
+    }
+}
+
+/// Returns the order `n` of the P-256 group.
+fn p256_order() -> BigNum {
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut order = BigNum::new().unwrap();
+    p256_group().order(&mut order, &mut ctx).unwrap();
+    order
+}
+
+fn to_coordinates<T>(key: &EcKey<T>, x: &mut [u8; EC_FIELD_SIZE], y: &mut [u8; EC_FIELD_SIZE])
+where
+    T: openssl::pkey::HasPublic,
+{
+    let group = p256_group();
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut bn_x = BigNum::new().unwrap();
+    let mut bn_y = BigNum::new().unwrap();
+    key.public_key()
+        .affine_coordinates_gfp(&group, &mut bn_x, &mut bn_y, &mut ctx)
+        .unwrap();
+    x.copy_from_slice(&bn_x.to_vec_padded(EC_FIELD_SIZE as i32).unwrap());
+    y.copy_from_slice(&bn_y.to_vec_padded(EC_FIELD_SIZE as i32).unwrap());
+}
+
+pub struct OpenSslEcdsaSignature {
+    der: Vec<u8>,
+}
+
+impl ecdsa::Signature for OpenSslEcdsaSignature {
+    fn from_slice(bytes: &[u8; EC_SIGNATURE_SIZE]) -> Option<Self> {
+        let r = BigNum::from_slice(&bytes[..EC_FIELD_SIZE]).ok()?;
+        let s = BigNum::from_slice(&bytes[EC_FIELD_SIZE..]).ok()?;
+        let signature = openssl::ecdsa::EcdsaSig::from_private_components(r, s).ok()?;
+        Some(OpenSslEcdsaSignature::new_normalized(signature))
+    }
+
+    fn to_der(&self) -> Vec<u8> {
+        self.der.clone()
+    }
+}
+
+impl OpenSslEcdsaSignature {
+    /// Wraps `sig`, normalizing `s` to its low-S form (`s <= n/2`) if needed.
+    ///
+    /// Both `(r, s)` and `(r, n - s)` verify for the same message, so without this
+    /// a signature isn't a unique encoding of "the authenticator signed this" and
+    /// can be malleated into a different, still-valid byte string.
+    fn new_normalized(sig: openssl::ecdsa::EcdsaSig) -> Self {
+        let order = p256_order();
+        let mut half_order = BigNum::new().unwrap();
+        order.rshift1(&mut half_order).unwrap();
+        let r = sig.r().to_owned().unwrap();
+        let s = sig.s().to_owned().unwrap();
+        let s = if s > half_order {
+            let mut negated = BigNum::new().unwrap();
+            negated.checked_sub(&order, &s).unwrap();
+            negated
+        } else {
+            s
+        };
+        let sig = openssl::ecdsa::EcdsaSig::from_private_components(r, s).unwrap();
+        let der = sig.to_der().unwrap();
+        OpenSslEcdsaSignature { der }
+    }
+
+    /// Returns whether `s` is already in its normalized, low-S form.
+    pub fn is_normalized(&self) -> bool {
+        let sig = openssl::ecdsa::EcdsaSig::from_der(&self.der).unwrap();
+        let order = p256_order();
+        let mut half_order = BigNum::new().unwrap();
+        order.rshift1(&mut half_order).unwrap();
+        sig.s() <= &half_order
+    }
+}
+
+pub struct OpenSslSha256 {
+    hasher: openssl::hash::Hasher,
+}
+
+impl Sha256 for OpenSslSha256 {
+    fn digest(data: &[u8]) -> [u8; HASH_SIZE] {
+        let digest = openssl::hash::hash(MessageDigest::sha256(), data).unwrap();
+        let mut result = [0; HASH_SIZE];
+        result.copy_from_slice(&digest);
+        result
+    }
+
+    fn new() -> Self {
+        let hasher = openssl::hash::Hasher::new(MessageDigest::sha256()).unwrap();
+        Self { hasher }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data).unwrap();
+    }
+
+    fn finalize(mut self) -> [u8; HASH_SIZE] {
+        let digest = self.hasher.finish().unwrap();
+        let mut result = [0; HASH_SIZE];
+        result.copy_from_slice(&digest);
+        result
+    }
+}
+
+pub struct OpenSslHmac256;
+
+impl Hmac256 for OpenSslHmac256 {
+    fn mac(key: &[u8; HMAC_KEY_SIZE], data: &[u8]) -> [u8; HASH_SIZE] {
+        let pkey = PKey::hmac(key).unwrap();
+        let mut signer = Signer::new(MessageDigest::sha256(), &pkey).unwrap();
+        let mac = signer.sign_oneshot_to_vec(data).unwrap();
+        let mut result = [0; HASH_SIZE];
+        result.copy_from_slice(&mac);
+        result
+    }
+
+    fn verify(key: &[u8; HMAC_KEY_SIZE], data: &[u8], mac: &[u8; HASH_SIZE]) -> bool {
+        openssl::memcmp::eq(&Self::mac(key, data), mac)
+    }
+
+    fn verify_truncated_left(
+        key: &[u8; HMAC_KEY_SIZE],
+        data: &[u8],
+        mac: &[u8; TRUNCATED_HMAC_SIZE],
+    ) -> bool {
+        openssl::memcmp::eq(&Self::mac(key, data)[..TRUNCATED_HMAC_SIZE], mac)
+    }
+}
+
+pub struct OpenSslHkdf256;
+
+impl Hkdf256 for OpenSslHkdf256 {
+    fn hkdf_empty_salt_256(ikm: &[u8], info: &[u8]) -> [u8; HASH_SIZE] {
+        let mut ctx = PkeyCtx::new_id(Id::HKDF).unwrap();
+        ctx.derive_init().unwrap();
+        ctx.set_hkdf_md(Md::sha256()).unwrap();
+        ctx.set_hkdf_salt(&[0; HASH_SIZE]).unwrap();
+        ctx.set_hkdf_key(ikm).unwrap();
+        ctx.add_hkdf_info(info).unwrap();
+        let mut okm = [0; HASH_SIZE];
+        ctx.derive(Some(&mut okm)).unwrap();
+        okm
+    }
+}
+
+impl Aes256 for OpenSslAes256 {
+    fn encrypt(enc_key: &[u8; AES_256_KEY_SIZE], plaintext: &[u8]) -> Option<Vec<u8>> {
+        let mut iv = [0; AES_256_BLOCK_SIZE];
+        rand_bytes(&mut iv).ok()?;
+        let mut crypter =
+            Crypter::new(Cipher::aes_256_cbc(), Mode::Encrypt, enc_key, Some(&iv)).ok()?;
+        // Always PKCS7-pad, even when `plaintext` is already block-aligned: CBC
+        // ciphertext is block-aligned either way, so `decrypt` has no reliable
+        // signal to tell an unpadded message from a padded one by length alone.
+        crypter.pad(true);
+        let mut ciphertext = Vec::new();
+        ciphertext.resize(plaintext.len() + AES_256_BLOCK_SIZE, 0);
+        let mut count = crypter.update(plaintext, &mut ciphertext).ok()?;
+        count += crypter.finalize(&mut ciphertext[count..]).ok()?;
+        ciphertext.truncate(count);
+        let mut result = Vec::with_capacity(AES_256_BLOCK_SIZE + ciphertext.len());
+        result.extend_from_slice(&iv);
+        result.extend_from_slice(&ciphertext);
+        Some(result)
+    }
+
+    fn decrypt(enc_key: &[u8; AES_256_KEY_SIZE], iv_and_ciphertext: &[u8]) -> Option<Vec<u8>> {
+        if iv_and_ciphertext.len() < AES_256_BLOCK_SIZE {
+            return None;
+        }
+        let (iv, ciphertext) = iv_and_ciphertext.split_at(AES_256_BLOCK_SIZE);
+        let mut crypter =
+            Crypter::new(Cipher::aes_256_cbc(), Mode::Decrypt, enc_key, Some(iv)).ok()?;
+        crypter.pad(true);
+        let mut plaintext = Vec::new();
+        plaintext.resize(ciphertext.len() + AES_256_BLOCK_SIZE, 0);
+        let mut count = crypter.update(ciphertext, &mut plaintext).ok()?;
+        count += crypter.finalize(&mut plaintext[count..]).ok()?;
+        plaintext.truncate(count);
+        Some(plaintext)
+    }
+
+    fn authenticate(mac_key: &[u8; HMAC_KEY_SIZE], iv_and_ciphertext: &[u8]) -> [u8; HASH_SIZE] {
+        OpenSslHmac256::mac(mac_key, iv_and_ciphertext)
+    }
+
+    fn verify(
+        mac_key: &[u8; HMAC_KEY_SIZE],
+        iv_and_ciphertext: &[u8],
+        mac: &[u8; HASH_SIZE],
+    ) -> bool {
+        OpenSslHmac256::verify(mac_key, iv_and_ciphertext, mac)
+    }
+}
+
+impl eddsa::Eddsa for OpenSslEddsa {
+    type SecretKey = OpenSslEddsaSecretKey;
+    type PublicKey = OpenSslEddsaPublicKey;
+    type Signature = OpenSslEddsaSignature;
+}
+
+pub struct OpenSslEddsaSecretKey {
+    key: PKey<Private>,
+}
+
+impl eddsa::SecretKey for OpenSslEddsaSecretKey {
+    type PublicKey = OpenSslEddsaPublicKey;
+    type Signature = OpenSslEddsaSignature;
+
+    fn random(_rng: &mut impl Rng256) -> Self {
+        let key = PKey::generate_ed25519().unwrap();
+        OpenSslEddsaSecretKey { key }
+    }
+
+    fn from_seed(seed: &[u8; ED_FIELD_SIZE]) -> Self {
+        let key = PKey::private_key_from_raw_bytes(seed, Id::ED25519).unwrap();
+        OpenSslEddsaSecretKey { key }
+    }
+
+    fn public_key(&self) -> Self::PublicKey {
+        let raw = self.key.raw_public_key().unwrap();
+        let key = PKey::public_key_from_raw_bytes(&raw, Id::ED25519).unwrap();
+        OpenSslEddsaPublicKey { key }
+    }
+
+    fn sign(&self, message: &[u8]) -> Self::Signature {
+        let mut signer = Signer::new_without_digest(&self.key).unwrap();
+        let bytes = signer.sign_oneshot_to_vec(message).unwrap();
+        let mut signature = [0; ED_SIGNATURE_SIZE];
+        signature.copy_from_slice(&bytes);
+        OpenSslEddsaSignature { signature }
+    }
+
+    fn to_seed(&self, seed: &mut [u8; ED_FIELD_SIZE]) {
+        let raw = self.key.raw_private_key().unwrap();
+        seed.copy_from_slice(&raw);
+    }
+}
+
+pub struct OpenSslEddsaPublicKey {
+    key: PKey<Public>,
+}
+
+impl eddsa::PublicKey for OpenSslEddsaPublicKey {
+    type Signature = OpenSslEddsaSignature;
+
+    fn from_bytes(bytes: &[u8; ED_FIELD_SIZE]) -> Option<Self> {
+        let key = PKey::public_key_from_raw_bytes(bytes, Id::ED25519).ok()?;
+        Some(OpenSslEddsaPublicKey { key })
+    }
+
+    fn verify(&self, message: &[u8], signature: &Self::Signature) -> bool {
+        let mut verifier = Verifier::new_without_digest(&self.key).unwrap();
+        verifier
+            .verify_oneshot(&signature.signature, message)
+            .unwrap_or(false)
+    }
+
+    fn to_bytes(&self, bytes: &mut [u8; ED_FIELD_SIZE]) {
+        let raw = self.key.raw_public_key().unwrap();
+        bytes.copy_from_slice(&raw);
+    }
+}
+
+pub struct OpenSslEddsaSignature {
+    signature: [u8; ED_SIGNATURE_SIZE],
+}
+
+impl eddsa::Signature for OpenSslEddsaSignature {
+    fn from_bytes(bytes: &[u8; ED_SIGNATURE_SIZE]) -> Option<Self> {
+        Some(OpenSslEddsaSignature { signature: *bytes })
+    }
+
+    fn to_bytes(&self) -> [u8; ED_SIGNATURE_SIZE] {
+        self.signature
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::crypto::ecdh::{
+        PublicKey as EcdhPublicKey, SecretKey as EcdhSecretKey, SharedSecret,
+    };
+    use crate::api::crypto::ecdsa::{
+        PublicKey as EcdsaPublicKey, SecretKey as EcdsaSecretKey, Signature as EcdsaSignature,
+    };
+    use crate::api::crypto::eddsa::{
+        PublicKey as EddsaPublicKey, SecretKey as EddsaSecretKey, Signature as EddsaSignature,
+    };
+    use crate::env::test::TestEnv;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_shared_secret_symmetric() {
+        let mut env = TestEnv::default();
+        let private1 = OpenSslEcdhSecretKey::random(env.rng());
+        let private2 = OpenSslEcdhSecretKey::random(env.rng());
+        let pub1 = private1.public_key();
+        let pub2 = private2.public_key();
+        let shared1 = private1.diffie_hellman(&pub2);
+        let shared2 = private2.diffie_hellman(&pub1);
+        assert_eq!(shared1.raw_secret_bytes(), shared2.raw_secret_bytes());
+    }
+
+    #[test]
+    fn test_ecdh_public_key_from_to_bytes() {
+        let mut env = TestEnv::default();
+        let first_key = OpenSslEcdhSecretKey::random(env.rng());
+        let first_public = first_key.public_key();
+        let mut x = [0; EC_FIELD_SIZE];
+        let mut y = [0; EC_FIELD_SIZE];
+        first_public.to_coordinates(&mut x, &mut y);
+        let new_public = OpenSslEcdhPublicKey::from_coordinates(&x, &y).unwrap();
+        let mut new_x = [0; EC_FIELD_SIZE];
+        let mut new_y = [0; EC_FIELD_SIZE];
+        new_public.to_coordinates(&mut new_x, &mut new_y);
+        assert_eq!(x, new_x);
+        assert_eq!(y, new_y);
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let mut env = TestEnv::default();
+        let private_key = OpenSslEcdsaSecretKey::random(env.rng());
+        let public_key = private_key.public_key();
+        let message = [0x12, 0x34, 0x56, 0x78];
+        let signature = private_key.sign(&message);
+        assert!(public_key.verify(&message, &signature));
+    }
+
+    #[test]
+    fn test_ecdsa_secret_key_from_to_bytes() {
+        let mut env = TestEnv::default();
+        let first_key = OpenSslEcdsaSecretKey::random(env.rng());
+        let mut key_bytes = [0; EC_FIELD_SIZE];
+        first_key.to_slice(&mut key_bytes);
+        let second_key = OpenSslEcdsaSecretKey::from_slice(&key_bytes).unwrap();
+        let mut new_bytes = [0; EC_FIELD_SIZE];
+        second_key.to_slice(&mut new_bytes);
+        assert_eq!(key_bytes, new_bytes);
+    }
+
+    #[test]
+    fn test_high_s_signature_is_normalized() {
+        let mut env = TestEnv::default();
+        let private_key = OpenSslEcdsaSecretKey::random(env.rng());
+        let public_key = private_key.public_key();
+        let message = [0x12, 0x34, 0x56, 0x78];
+        let signature = private_key.sign(&message);
+        assert!(signature.is_normalized());
+
+        // Build the high-S variant (r, n - s) of the same signature by hand and
+        // feed it back through `from_slice`, which must normalize it right back.
+        let sig = openssl::ecdsa::EcdsaSig::from_der(&signature.to_der()).unwrap();
+        let order = p256_order();
+        let mut high_s = BigNum::new().unwrap();
+        high_s.checked_sub(&order, sig.s()).unwrap();
+        let high_s_signature =
+            openssl::ecdsa::EcdsaSig::from_private_components(sig.r().to_owned().unwrap(), high_s)
+                .unwrap();
+        let mut high_s_bytes = [0; EC_SIGNATURE_SIZE];
+        high_s_bytes[..EC_FIELD_SIZE]
+            .copy_from_slice(&sig.r().to_vec_padded(EC_FIELD_SIZE as i32).unwrap());
+        high_s_bytes[EC_FIELD_SIZE..].copy_from_slice(
+            &high_s_signature
+                .s()
+                .to_vec_padded(EC_FIELD_SIZE as i32)
+                .unwrap(),
+        );
+
+        let normalized = OpenSslEcdsaSignature::from_slice(&high_s_bytes).unwrap();
+        assert!(normalized.is_normalized());
+        assert!(public_key.verify(&message, &normalized));
+        assert!(public_key.verify_strict(&message, &normalized));
+    }
+
+    #[test]
+    fn test_eddsa_sign_verify() {
+        let mut env = TestEnv::default();
+        let private_key = OpenSslEddsaSecretKey::random(env.rng());
+        let public_key = private_key.public_key();
+        let message = [0x12, 0x34, 0x56, 0x78];
+        let signature = private_key.sign(&message);
+        assert!(public_key.verify(&message, &signature));
+    }
+
+    #[test]
+    fn test_eddsa_secret_key_from_to_seed() {
+        let mut env = TestEnv::default();
+        let first_key = OpenSslEddsaSecretKey::random(env.rng());
+        let mut seed = [0; ED_FIELD_SIZE];
+        first_key.to_seed(&mut seed);
+        let second_key = OpenSslEddsaSecretKey::from_seed(&seed);
+        let mut new_seed = [0; ED_FIELD_SIZE];
+        second_key.to_seed(&mut new_seed);
+        assert_eq!(seed, new_seed);
+    }
+
+    #[test]
+    fn test_sha256_hash_matches() {
+        let data = [0x55; 16];
+        let mut hasher = OpenSslSha256::new();
+        hasher.update(&data);
+        assert_eq!(OpenSslSha256::digest(&data), hasher.finalize());
+    }
+
+    #[test]
+    fn test_hmac256_verifies() {
+        let key = [0xAA; HMAC_KEY_SIZE];
+        let data = [0x55; 16];
+        let mac = OpenSslHmac256::mac(&key, &data);
+        assert!(OpenSslHmac256::verify(&key, &data, &mac));
+        let truncated_mac =
+            <&[u8; TRUNCATED_HMAC_SIZE]>::try_from(&mac[..TRUNCATED_HMAC_SIZE]).unwrap();
+        assert!(OpenSslHmac256::verify_truncated_left(
+            &key,
+            &data,
+            &truncated_mac
+        ));
+    }
+
+    #[test]
+    fn test_aes256_encrypt_decrypt_round_trip() {
+        let enc_key = [0x42; AES_256_KEY_SIZE];
+        let mac_key = [0x43; HMAC_KEY_SIZE];
+        let plaintext = [0x11; 32];
+        let iv_and_ciphertext = OpenSslAes256::encrypt(&enc_key, &plaintext).unwrap();
+        let mac = OpenSslAes256::authenticate(&mac_key, &iv_and_ciphertext);
+        assert!(OpenSslAes256::verify(&mac_key, &iv_and_ciphertext, &mac));
+        let decrypted = OpenSslAes256::decrypt(&enc_key, &iv_and_ciphertext).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_aes256_encrypt_decrypt_unaligned_round_trip() {
+        let enc_key = [0x24; AES_256_KEY_SIZE];
+        let plaintext = [0x99; 20];
+        let iv_and_ciphertext = OpenSslAes256::encrypt(&enc_key, &plaintext).unwrap();
+        let decrypted = OpenSslAes256::decrypt(&enc_key, &iv_and_ciphertext).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_aes256_encrypt_decrypt_round_trip_with_padding_like_trailer() {
+        // Block-aligned plaintext whose trailing bytes happen to look like valid
+        // PKCS7 padding. Regression test: decrypt must not mistake this for an
+        // (incorrectly) unpadded block and strip bytes that are real plaintext.
+        let enc_key = [0x77; AES_256_KEY_SIZE];
+        let plaintext = [0x10; 16];
+        let iv_and_ciphertext = OpenSslAes256::encrypt(&enc_key, &plaintext).unwrap();
+        let decrypted = OpenSslAes256::decrypt(&enc_key, &iv_and_ciphertext).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_aes256_verify_rejects_tampered_mac() {
+        let enc_key = [0x13; AES_256_KEY_SIZE];
+        let mac_key = [0x14; HMAC_KEY_SIZE];
+        let plaintext = [0x01; 16];
+        let iv_and_ciphertext = OpenSslAes256::encrypt(&enc_key, &plaintext).unwrap();
+        let mut bad_mac = OpenSslAes256::authenticate(&mac_key, &iv_and_ciphertext);
+        bad_mac[0] ^= 0xFF;
+        assert!(!OpenSslAes256::verify(
+            &mac_key,
+            &iv_and_ciphertext,
+            &bad_mac
+        ));
+    }
+
+    #[test]
+    fn test_hkdf_empty_salt_256_vector() {
+        let okm = [
+            0xf9, 0xbe, 0x72, 0x11, 0x6c, 0xb9, 0x7f, 0x41, 0x82, 0x82, 0x10, 0x28, 0x9c, 0xaa,
+            0xfe, 0xab, 0xde, 0x1f, 0x3d, 0xfb, 0x97, 0x23, 0xbf, 0x43, 0x53, 0x8a, 0xb1, 0x8f,
+            0x36, 0x66, 0x78, 0x3a,
+        ];
+        assert_eq!(&OpenSslHkdf256::hkdf_empty_salt_256(b"0", &[0]), &okm);
+    }
+}